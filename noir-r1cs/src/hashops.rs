@@ -1,14 +1,47 @@
 use {
-    crate::noir_to_r1cs::{
-        NoirToR1CSCompiler,
-        ConstantOrR1CSWitness,
+    crate::{
+        noir_to_r1cs::{ConstantOrR1CSWitness, NoirToR1CSCompiler},
+        FieldElement,
     },
-    // acvm_blackbox_solver::blake2s,
     tracing::info,
 };
 
+/// Blake2s initialization vector (the first 32 bits of the fractional parts
+/// of the square roots of the first 8 primes).
+const IV: [u32; 8] = [
+    0x6A09_E667,
+    0xBB67_AE85,
+    0x3C6E_F372,
+    0xA54F_F53A,
+    0x510E_527F,
+    0x9B05_688C,
+    0x1F83_D9AB,
+    0x5BE0_CD19,
+];
+
+/// Blake2s message word permutation, one row per round.
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+const BLOCK_SIZE: usize = 64;
+const NUM_ROUNDS: usize = 10;
+
+/// A 32-bit word, represented as 32 boolean witnesses, least-significant bit
+/// first.
+type Word = [usize; 32];
+
 /// Generates R1CS constraints for the Blake2s hash function.
-/// 
+///
 /// - inputs are a byte array, i.e a vector of (witness, 8)
 /// - output is a byte array of length 32, i.e. an array of 32 (witness, 8)
 pub fn add_blake2s_constraints(
@@ -21,8 +54,310 @@ pub fn add_blake2s_constraints(
     info!("outputs.len(): {}", outputs.len());
     info!("numWitnesses: {}", compiler.num_witnesses());
 
-    // TODO: Add constraints for the Blake2s hash function
-    // This is a placeholder - in a real implementation, we would add the actual
-    // Blake2s constraints here. For now, we just ensure the inputs and outputs
-    // are properly constrained as bytes.
-} 
\ No newline at end of file
+    assert_eq!(outputs.len(), 32, "blake2s digest is always 32 bytes");
+
+    // Decompose every input byte into 8 boolean witnesses, LSB first.
+    let message_len = inputs.len();
+    let mut bits: Vec<[usize; 8]> = inputs
+        .iter()
+        .map(|byte| decompose_input_byte(compiler, byte))
+        .collect();
+
+    // Pad the message to a block boundary. Blake2s (unlike Blake2b) does not
+    // mix in any special padding bytes: trailing bytes of the final block are
+    // simply treated as zero, and `t` tracks the true message length.
+    let num_blocks = message_len.div_ceil(BLOCK_SIZE).max(1);
+    let zero_byte = [compiler.add_constant(FieldElement::from(0u64)); 8];
+    bits.resize(num_blocks * BLOCK_SIZE, zero_byte);
+
+    // h[0..8] = IV, with h[0] additionally XORed with the parameter word
+    // 0x01010020 (digest length 32, fanout 1, depth 1, all else default).
+    let mut h: [Word; 8] =
+        core::array::from_fn(|i| alloc_constant_word(compiler, IV[i] ^ if i == 0 { 0x0101_0020 } else { 0 }));
+
+    for (block_idx, block) in bits.chunks(BLOCK_SIZE).enumerate() {
+        let is_final_block = block_idx == num_blocks - 1;
+        let t = (((block_idx + 1) * BLOCK_SIZE).min(message_len)) as u64;
+        h = compress(compiler, &h, block, t, is_final_block);
+    }
+
+    // Bind the output byte witnesses to the little-endian bytes of h.
+    for (word_idx, word) in h.iter().enumerate() {
+        for byte_idx in 0..4 {
+            let byte_bits = &word[byte_idx * 8..byte_idx * 8 + 8];
+            bind_byte(compiler, byte_bits, outputs[word_idx * 4 + byte_idx]);
+        }
+    }
+}
+
+/// Runs the Blake2s compression function `F` on one 64-byte message block,
+/// returning the updated chaining value.
+fn compress(
+    compiler: &mut NoirToR1CSCompiler,
+    h: &[Word; 8],
+    block: &[[usize; 8]],
+    t: u64,
+    is_final_block: bool,
+) -> [Word; 8] {
+    let m: [Word; 16] = core::array::from_fn(|i| word_from_bytes(&block[i * 4..i * 4 + 4]));
+
+    let t_low = alloc_constant_word(compiler, (t & 0xFFFF_FFFF) as u32);
+    let t_high = alloc_constant_word(compiler, (t >> 32) as u32);
+    let inverted = alloc_constant_word(compiler, 0xFFFF_FFFF);
+
+    let mut v: [Word; 16] = core::array::from_fn(|i| if i < 8 { h[i] } else { alloc_constant_word(compiler, IV[i - 8]) });
+    v[12] = xor_words(compiler, &v[12], &t_low);
+    v[13] = xor_words(compiler, &v[13], &t_high);
+    if is_final_block {
+        v[14] = xor_words(compiler, &v[14], &inverted);
+    }
+
+    for round in 0..NUM_ROUNDS {
+        let s = &SIGMA[round];
+        g(compiler, &mut v, 0, 4, 8, 12, &m[s[0]], &m[s[1]]);
+        g(compiler, &mut v, 1, 5, 9, 13, &m[s[2]], &m[s[3]]);
+        g(compiler, &mut v, 2, 6, 10, 14, &m[s[4]], &m[s[5]]);
+        g(compiler, &mut v, 3, 7, 11, 15, &m[s[6]], &m[s[7]]);
+        g(compiler, &mut v, 0, 5, 10, 15, &m[s[8]], &m[s[9]]);
+        g(compiler, &mut v, 1, 6, 11, 12, &m[s[10]], &m[s[11]]);
+        g(compiler, &mut v, 2, 7, 8, 13, &m[s[12]], &m[s[13]]);
+        g(compiler, &mut v, 3, 4, 9, 14, &m[s[14]], &m[s[15]]);
+    }
+
+    core::array::from_fn(|i| {
+        let hi_xor_vi = xor_words(compiler, &h[i], &v[i]);
+        xor_words(compiler, &hi_xor_vi, &v[i + 8])
+    })
+}
+
+/// The Blake2s mixing function, applied to one column or diagonal of `v`.
+fn g(compiler: &mut NoirToR1CSCompiler, v: &mut [Word; 16], a: usize, b: usize, c: usize, d: usize, x: &Word, y: &Word) {
+    v[a] = add_mod32(compiler, &[&v[a], &v[b], x]);
+    v[d] = rotr(&xor_words(compiler, &v[d], &v[a]), 16);
+    v[c] = add_mod32(compiler, &[&v[c], &v[d]]);
+    v[b] = rotr(&xor_words(compiler, &v[b], &v[c]), 12);
+
+    v[a] = add_mod32(compiler, &[&v[a], &v[b], y]);
+    v[d] = rotr(&xor_words(compiler, &v[d], &v[a]), 8);
+    v[c] = add_mod32(compiler, &[&v[c], &v[d]]);
+    v[b] = rotr(&xor_words(compiler, &v[b], &v[c]), 7);
+}
+
+/// Bitwise rotation of a 32-bit word to the right. This is a pure relabeling
+/// of existing witnesses and adds no constraints.
+fn rotr(word: &Word, n: usize) -> Word {
+    core::array::from_fn(|i| word[(i + n) % 32])
+}
+
+/// Constrains and returns `a XOR b`, bit by bit, using `xor = a + b - 2*a*b`.
+fn xor_words(compiler: &mut NoirToR1CSCompiler, a: &Word, b: &Word) -> Word {
+    core::array::from_fn(|i| xor_bit(compiler, a[i], b[i]))
+}
+
+fn xor_bit(compiler: &mut NoirToR1CSCompiler, a: usize, b: usize) -> usize {
+    let one = compiler.one();
+    let ab = compiler.add_witness();
+    compiler.assert_r1cs(&[(FieldElement::from(1u64), a)], &[(FieldElement::from(1u64), b)], &[(
+        FieldElement::from(1u64),
+        ab,
+    )]);
+
+    let result = compiler.add_witness();
+    compiler.assert_r1cs(
+        &[(FieldElement::from(1u64), result)],
+        &[(FieldElement::from(1u64), one)],
+        &[
+            (FieldElement::from(1u64), a),
+            (FieldElement::from(1u64), b),
+            (-FieldElement::from(2u64), ab),
+        ],
+    );
+    result
+}
+
+/// Adds up to three 32-bit words modulo 2^32, constraining and discarding the
+/// carry bits.
+fn add_mod32(compiler: &mut NoirToR1CSCompiler, words: &[&Word]) -> Word {
+    assert!(words.len() <= 3, "at most 3 summands fit in the field margin used here");
+    let one = compiler.one();
+
+    // `a + b [+ x]` of three 32-bit values needs at most 34 bits to hold the
+    // carry.
+    let num_result_bits = 34;
+    let result_bits: Vec<usize> = (0..num_result_bits).map(|_| alloc_boolean(compiler)).collect();
+
+    let mut lhs: Vec<(FieldElement, usize)> = Vec::new();
+    for word in words {
+        for (i, &bit) in word.iter().enumerate() {
+            lhs.push((FieldElement::from(1u64 << i), bit));
+        }
+    }
+    let rhs: Vec<(FieldElement, usize)> = result_bits
+        .iter()
+        .enumerate()
+        .map(|(i, &bit)| (FieldElement::from(1u128 << i), bit))
+        .collect();
+
+    compiler.assert_r1cs(&[(FieldElement::from(1u64), one)], &lhs, &rhs);
+
+    core::array::from_fn(|i| result_bits[i])
+}
+
+/// Allocates a fresh boolean witness, constrained via `b * (b - 1) = 0`.
+fn alloc_boolean(compiler: &mut NoirToR1CSCompiler) -> usize {
+    let one = compiler.one();
+    let b = compiler.add_witness();
+    compiler.assert_r1cs(
+        &[(FieldElement::from(1u64), b)],
+        &[(FieldElement::from(1u64), b), (-FieldElement::from(1u64), one)],
+        &[],
+    );
+    b
+}
+
+/// Allocates a word of 32 fixed boolean witnesses equal to `value`.
+fn alloc_constant_word(compiler: &mut NoirToR1CSCompiler, value: u32) -> Word {
+    core::array::from_fn(|i| compiler.add_constant(FieldElement::from((value >> i) & 1)))
+}
+
+/// Decomposes one message byte into 8 boolean witnesses and constrains them
+/// to recompose to the original input value.
+fn decompose_input_byte(compiler: &mut NoirToR1CSCompiler, input: &ConstantOrR1CSWitness) -> [usize; 8] {
+    let one = compiler.one();
+    let bits: [usize; 8] = core::array::from_fn(|_| alloc_boolean(compiler));
+    let bits_lc: Vec<(FieldElement, usize)> = bits
+        .iter()
+        .enumerate()
+        .map(|(i, &bit)| (FieldElement::from(1u64 << i), bit))
+        .collect();
+
+    match *input {
+        ConstantOrR1CSWitness::Constant(value) => {
+            compiler.assert_r1cs(&[(value, one)], &[(FieldElement::from(1u64), one)], &bits_lc);
+        }
+        ConstantOrR1CSWitness::Witness(witness) => {
+            compiler.assert_r1cs(&[(FieldElement::from(1u64), witness)], &[(FieldElement::from(1u64), one)], &bits_lc);
+        }
+    }
+    bits
+}
+
+/// Constrains `output` to equal the byte formed by `bits` (LSB first).
+fn bind_byte(compiler: &mut NoirToR1CSCompiler, bits: &[usize], output: usize) {
+    let one = compiler.one();
+    let bits_lc: Vec<(FieldElement, usize)> = bits
+        .iter()
+        .enumerate()
+        .map(|(i, &bit)| (FieldElement::from(1u64 << i), bit))
+        .collect();
+    compiler.assert_r1cs(&[(FieldElement::from(1u64), output)], &[(FieldElement::from(1u64), one)], &bits_lc);
+}
+
+/// Packs 4 little-endian bytes (each 8 boolean witnesses) into one 32-bit
+/// word.
+fn word_from_bytes(bytes: &[[usize; 8]]) -> Word {
+    core::array::from_fn(|i| bytes[i / 8][i % 8])
+}
+
+#[cfg(test)]
+mod tests {
+    //! `add_blake2s_constraints` only runs against a live
+    //! `NoirToR1CSCompiler`, so it can't be exercised directly here. These
+    //! tests instead check the round/permutation/padding structure it
+    //! implements, by mirroring it in plain `u32` arithmetic (same `IV`,
+    //! `SIGMA`, round count, and `G` call order as `compress`/`g` above) and
+    //! comparing against known BLAKE2s-256 digests.
+
+    use super::{BLOCK_SIZE, IV, NUM_ROUNDS, SIGMA};
+
+    fn g(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(12);
+
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+        v[d] = (v[d] ^ v[a]).rotate_right(8);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(7);
+    }
+
+    fn compress(h: &mut [u32; 8], block: &[u8], t: u64, is_final_block: bool) {
+        let m: [u32; 16] = core::array::from_fn(|i| u32::from_le_bytes(block[i * 4..i * 4 + 4].try_into().unwrap()));
+
+        let mut v: [u32; 16] = core::array::from_fn(|i| if i < 8 { h[i] } else { IV[i - 8] });
+        v[12] ^= (t & 0xFFFF_FFFF) as u32;
+        v[13] ^= (t >> 32) as u32;
+        if is_final_block {
+            v[14] ^= 0xFFFF_FFFF;
+        }
+
+        for round in 0..NUM_ROUNDS {
+            let s = &SIGMA[round];
+            g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+            g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+            g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+            g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+            g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+            g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+            g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+            g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        }
+
+        for i in 0..8 {
+            h[i] ^= v[i] ^ v[i + 8];
+        }
+    }
+
+    fn blake2s_256(message: &[u8]) -> [u8; 32] {
+        let mut h = IV;
+        h[0] ^= 0x0101_0020;
+
+        let num_blocks = message.len().div_ceil(BLOCK_SIZE).max(1);
+        let mut padded = message.to_vec();
+        padded.resize(num_blocks * BLOCK_SIZE, 0);
+
+        for (block_idx, block) in padded.chunks(BLOCK_SIZE).enumerate() {
+            let is_final_block = block_idx == num_blocks - 1;
+            let t = (((block_idx + 1) * BLOCK_SIZE).min(message.len())) as u64;
+            compress(&mut h, block, t, is_final_block);
+        }
+
+        let mut digest = [0u8; 32];
+        for (i, word) in h.iter().enumerate() {
+            digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_le_bytes());
+        }
+        digest
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn blake2s_256_empty_message() {
+        assert_eq!(
+            hex(&blake2s_256(b"")),
+            "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9"
+        );
+    }
+
+    #[test]
+    fn blake2s_256_abc() {
+        assert_eq!(
+            hex(&blake2s_256(b"abc")),
+            "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"
+        );
+    }
+
+    #[test]
+    fn blake2s_256_spans_multiple_blocks() {
+        // 65 bytes forces the 2-block / final-block-flag path, exercising
+        // the `t` chaining and the final-block XOR of `v[14]`.
+        let message = [0x61u8; 65];
+        assert_eq!(
+            hex(&blake2s_256(&message)),
+            "045f8ae18932119bd051ac7ba5c73db59892055fad5c32f82d79a6543d92a497"
+        );
+    }
+}
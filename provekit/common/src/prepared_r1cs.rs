@@ -0,0 +1,56 @@
+//! Caches an [`R1CS`] instance behind a save/load-able handle, so a server
+//! can compile a circuit once and reuse it across many `prove` calls instead
+//! of re-reading it from the `Prover` (or disk) each time.
+//!
+//! Mirrors the pre-calculated `ConstraintMatrices` approach used on the
+//! circom-compat groth16 path: compile a circuit once into a `PreparedR1CS`,
+//! save it, and load it back for later proofs.
+//!
+//! As shipped, `PreparedR1CS` wraps `R1CS` without building or caching
+//! anything beyond it — it only avoids recompiling/re-reading the circuit
+//! definition itself. It does **not** amortize per-proof constraint-matrix
+//! construction: `solve_witness_vec`/`WhirR1CSScheme::prove` still take a
+//! plain `R1CS` and do whatever matrix construction they always did, on every
+//! call, identically to not having a `PreparedR1CS` at all. Threading
+//! pre-built matrices through those entrypoints (the actual expensive work
+//! worth amortizing) requires changes to `R1CS`/`WhirR1CSScheme` themselves,
+//! which is out of scope here.
+
+use {
+    crate::R1CS,
+    anyhow::{Context, Result},
+    ark_ff::PrimeField,
+    serde::{Deserialize, Serialize},
+    std::{
+        fs::File,
+        io::{BufReader, BufWriter},
+        path::Path,
+    },
+};
+
+/// A cached `R1CS` instance, serializable so it can be saved once and loaded
+/// back without recompiling the circuit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreparedR1CS<F: PrimeField> {
+    pub r1cs: R1CS<F>,
+}
+
+impl<F: PrimeField> PreparedR1CS<F> {
+    pub fn prepare(r1cs: R1CS<F>) -> Self {
+        Self { r1cs }
+    }
+
+    /// Serializes this prepared instance to `path` so it can be loaded back
+    /// without recompiling the circuit.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let file = BufWriter::new(File::create(path).context("While creating prepared R1CS file")?);
+        bincode::serialize_into(file, self).context("While serializing prepared R1CS")?;
+        Ok(())
+    }
+
+    /// Loads a previously saved [`PreparedR1CS`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let file = BufReader::new(File::open(path).context("While opening prepared R1CS file")?);
+        bincode::deserialize_from(file).context("While deserializing prepared R1CS")
+    }
+}
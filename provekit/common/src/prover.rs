@@ -1,39 +1,132 @@
 use {
     crate::{
         noir_proof_scheme::NoirProofScheme,
+        prepared_r1cs::PreparedR1CS,
         whir_r1cs::WhirR1CSScheme,
         witness::{NoirWitnessGenerator, SplitWitnessBuilders},
-        NoirElement, R1CS,
+        FieldElement, NoirElement, R1CS,
     },
     acir::circuit::Program,
+    ark_ff::PrimeField,
     serde::{Deserialize, Serialize},
+    thiserror::Error,
 };
 
-/// A prover for a Noir Proof Scheme
+/// Errors accessing or driving a [`Prover`].
+#[derive(Debug, Error)]
+pub enum ProverError {
+    #[error("prover is missing its compiled Noir program")]
+    MissingProgram,
+    #[error("prover is missing its compiled R1CS instance")]
+    MissingR1CS,
+    #[error("prover is missing its split witness builders")]
+    MissingSplitWitnessBuilders,
+    #[error("prover is missing its witness generator")]
+    MissingWitnessGenerator,
+    #[error("prover is missing its WHIR scheme")]
+    MissingWhirForWitness,
+    #[error("witness generation failed: {0}")]
+    WitnessGenerationFailed(String),
+    #[error(
+        "deterministic masking is not supported yet: it requires threading a mask seed into \
+         WhirR1CSScheme::prove, which isn't wired up"
+    )]
+    DeterministicMaskingUnsupported,
+}
+
+/// A prover for a Noir Proof Scheme.
+///
+/// Generic over the proving field `F`, so that the data this type holds (the
+/// R1CS instance and the WHIR scheme) isn't hardcoded to BN254. `F` defaults
+/// to [`FieldElement`] so existing callers are unaffected. The compiled
+/// Noir program itself stays tied to [`NoirElement`], the fixed field ACIR
+/// circuits are defined over, independent of the proving field.
+///
+/// This is only a data-layer genericity, not a multi-curve prover: the
+/// `Prove` trait that drives witness generation and proving is implemented
+/// solely for `Prover<FieldElement>`, since it seeds a `SkyscraperSponge`
+/// Fiat-Shamir transcript, which is itself specific to that field. The
+/// `R1CSSolver`/`WhirR1CSProver` traits `Prove` relies on for the actual
+/// solving/proving work are likewise still `FieldElement`-only and were not
+/// made generic. Actually targeting another curve needs a field-generic
+/// sponge and generic `R1CSSolver`/`WhirR1CSProver` impls as well, neither of
+/// which is done here.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Prover {
+pub struct Prover<F: PrimeField = FieldElement> {
     pub program:                Option<Program<NoirElement>>,
-    pub r1cs:                   Option<R1CS>,
+    pub r1cs:                   Option<R1CS<F>>,
     pub split_witness_builders: Option<SplitWitnessBuilders>,
     pub witness_generator:      Option<NoirWitnessGenerator>,
-    pub whir_for_witness:       Option<WhirR1CSScheme>,
+    pub whir_for_witness:       Option<WhirR1CSScheme<F>>,
+    /// The cached `R1CS`, wrapped so it can be saved/loaded without
+    /// recompiling the circuit. Built lazily on first use via
+    /// [`Prover::prepared_r1cs`] and reused by every later `prove` call on
+    /// this `Prover`.
+    #[serde(skip)]
+    pub prepared_r1cs:          Option<PreparedR1CS<F>>,
 }
 
-impl Prover {
-    pub fn from_noir_proof_scheme(noir_proof_scheme: NoirProofScheme) -> Self {
+impl<F: PrimeField> Prover<F> {
+    pub fn from_noir_proof_scheme(noir_proof_scheme: NoirProofScheme<F>) -> Self {
         Self {
             program:                Some(noir_proof_scheme.program),
             r1cs:                   Some(noir_proof_scheme.r1cs),
             split_witness_builders: Some(noir_proof_scheme.split_witness_builders),
             witness_generator:      Some(noir_proof_scheme.witness_generator),
             whir_for_witness:       Some(noir_proof_scheme.whir_for_witness),
+            prepared_r1cs:          None,
         }
     }
 
-    pub const fn size(&self) -> (usize, usize) {
-        (
-            self.r1cs.as_ref().unwrap().num_constraints(),
-            self.r1cs.as_ref().unwrap().num_witnesses(),
-        )
+    /// The compiled Noir program, or an error if this `Prover` was not fully
+    /// constructed.
+    pub fn program(&self) -> Result<&Program<NoirElement>, ProverError> {
+        self.program.as_ref().ok_or(ProverError::MissingProgram)
+    }
+
+    /// The compiled R1CS instance, or an error if this `Prover` was not fully
+    /// constructed.
+    pub fn r1cs(&self) -> Result<&R1CS<F>, ProverError> {
+        self.r1cs.as_ref().ok_or(ProverError::MissingR1CS)
+    }
+
+    /// The split witness builders, or an error if this `Prover` was not fully
+    /// constructed.
+    pub fn split_witness_builders(&self) -> Result<&SplitWitnessBuilders, ProverError> {
+        self.split_witness_builders
+            .as_ref()
+            .ok_or(ProverError::MissingSplitWitnessBuilders)
+    }
+
+    /// The witness generator, or an error if this `Prover` was not fully
+    /// constructed.
+    pub fn witness_generator(&self) -> Result<&NoirWitnessGenerator, ProverError> {
+        self.witness_generator
+            .as_ref()
+            .ok_or(ProverError::MissingWitnessGenerator)
+    }
+
+    /// The WHIR scheme used to prove the R1CS instance, or an error if this
+    /// `Prover` was not fully constructed.
+    pub fn whir_for_witness(&self) -> Result<&WhirR1CSScheme<F>, ProverError> {
+        self.whir_for_witness
+            .as_ref()
+            .ok_or(ProverError::MissingWhirForWitness)
+    }
+
+    pub fn size(&self) -> Result<(usize, usize), ProverError> {
+        let r1cs = self.r1cs()?;
+        Ok((r1cs.num_constraints(), r1cs.num_witnesses()))
+    }
+
+    /// The cached [`PreparedR1CS`] wrapping a clone of `r1cs`, created on
+    /// first call and returned as-is by later calls on this `Prover`. No
+    /// constraint matrices are built or cached beyond the `R1CS` itself.
+    pub fn prepared_r1cs(&mut self) -> Result<&PreparedR1CS<F>, ProverError> {
+        if self.prepared_r1cs.is_none() {
+            let r1cs = self.r1cs()?.clone();
+            self.prepared_r1cs = Some(PreparedR1CS::prepare(r1cs));
+        }
+        Ok(self.prepared_r1cs.as_ref().expect("just populated above"))
     }
 }
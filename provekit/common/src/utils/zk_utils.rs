@@ -1,22 +1,21 @@
 use {
-    crate::FieldElement,
-    ark_ff::{BigInt, UniformRand, PrimeField},
+    ark_ff::{PrimeField, UniformRand},
     rayon::prelude::*,
     sha2::{Digest, Sha256},
     whir::poly_utils::evals::EvaluationsList,
 };
 
-pub fn create_masked_polynomial(
-    original: EvaluationsList<FieldElement>,
-    mask: &[FieldElement],
-) -> EvaluationsList<FieldElement> {
+pub fn create_masked_polynomial<F: PrimeField>(
+    original: EvaluationsList<F>,
+    mask: &[F],
+) -> EvaluationsList<F> {
     let mut combined = Vec::with_capacity(original.num_evals() * 2);
     combined.extend_from_slice(original.evals());
     combined.extend_from_slice(mask);
     EvaluationsList::new(combined)
 }
 
-pub fn generate_random_multilinear_polynomial(num_vars: usize) -> Vec<FieldElement> {
+pub fn generate_random_multilinear_polynomial<F: PrimeField>(num_vars: usize) -> Vec<F> {
     let num_elements = 1 << num_vars;
     let mut elements = Vec::with_capacity(num_elements);
 
@@ -30,7 +29,7 @@ pub fn generate_random_multilinear_polynomial(num_vars: usize) -> Vec<FieldEleme
     spare.par_chunks_mut(CHUNK_SIZE).for_each(|chunk| {
         let mut rng = ark_std::rand::thread_rng();
         for element in chunk {
-            element.write(FieldElement::rand(&mut rng));
+            element.write(F::rand(&mut rng));
         }
     });
 
@@ -44,20 +43,23 @@ pub fn generate_random_multilinear_polynomial(num_vars: usize) -> Vec<FieldEleme
 /// Hashes public input values.
 ///
 /// This function takes public indices and their corresponding witness values,
-/// hashes them using SHA-256, and converts the result to a FieldElement.
-pub fn hash_public_values(public_indices: Vec<usize>, witness: Vec<FieldElement>) -> FieldElement {
+/// hashes them using SHA-256, and converts the result to a field element.
+///
+/// The number of 64-bit limbs absorbed from the digest is derived from
+/// `F::MODULUS_BIT_SIZE` rather than assumed to be four, so this works for
+/// fields other than the 256-bit BN254 scalar field.
+pub fn hash_public_values<F: PrimeField>(public_indices: Vec<usize>, witness: Vec<F>) -> F {
     let mut hasher = Sha256::new();
     for (_idx, value) in public_indices.iter().zip(witness.iter()) {
-        for limb in value.into_bigint().0.iter() {
-            hasher.update(&limb.to_le_bytes());
+        for limb in value.into_bigint().as_ref().iter() {
+            hasher.update(limb.to_le_bytes());
         }
     }
     let result = hasher.finalize();
 
-    let limbs = result
-        .chunks_exact(8)
-        .map(|s| u64::from_le_bytes(s.try_into().unwrap()))
-        .collect::<Vec<_>>();
+    let num_limbs = (F::MODULUS_BIT_SIZE as usize).div_ceil(64);
+    let num_bytes = (num_limbs * 8).min(result.len());
+
+    F::from_le_bytes_mod_order(&result[..num_bytes])
+}
 
-    FieldElement::new(BigInt::new(limbs.try_into().unwrap()))
-}
\ No newline at end of file
@@ -0,0 +1,194 @@
+//! C FFI surface for embedding [`Prover`] in non-Rust hosts (mobile, wasm).
+//!
+//! `Prover` is exposed as an opaque handle. Inputs and outputs cross the
+//! boundary as [`Buffer`]s (a pointer + length the caller owns on the way in,
+//! and that this module allocates and hands ownership of on the way out, to
+//! be freed with [`provekit_buffer_free`]). Every entrypoint that can fail
+//! reports success via a `bool` return rather than panicking, so a malformed
+//! call from the host language cannot abort the process.
+
+use {
+    crate::{MaskingMode, Prove},
+    provekit_common::{FieldElement, NoirProof, NoirProofScheme, Prover},
+    std::{io::Write, panic, ptr, slice},
+    tempfile::NamedTempFile,
+};
+
+/// A borrowed-in / owned-out byte buffer crossing the FFI boundary.
+#[repr(C)]
+pub struct Buffer {
+    pub data: *mut u8,
+    pub len:  usize,
+}
+
+impl Buffer {
+    /// # Safety
+    /// `data` must point to at least `len` initialized bytes for the
+    /// lifetime of this call.
+    unsafe fn as_slice(&self) -> &[u8] {
+        if self.data.is_null() || self.len == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(self.data, self.len)
+        }
+    }
+
+    fn empty() -> Self {
+        Self {
+            data: ptr::null_mut(),
+            len:  0,
+        }
+    }
+
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        let mut bytes = bytes.into_boxed_slice();
+        let buffer = Self {
+            data: bytes.as_mut_ptr(),
+            len:  bytes.len(),
+        };
+        std::mem::forget(bytes);
+        buffer
+    }
+}
+
+/// Frees a [`Buffer`] previously returned by this module. Safe to call on an
+/// already-empty buffer.
+///
+/// # Safety
+/// `buffer` must have been produced by this module and not freed already.
+#[no_mangle]
+pub unsafe extern "C" fn provekit_buffer_free(buffer: Buffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(slice::from_raw_parts_mut(
+            buffer.data,
+            buffer.len,
+        )));
+    }
+}
+
+/// Runs `f`, catching panics so they can never unwind across the FFI
+/// boundary, and reports success as a `bool`.
+fn catch<T>(out: &mut Buffer, f: impl FnOnce() -> anyhow::Result<Vec<u8>>) -> bool {
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    match result {
+        Ok(Ok(bytes)) => {
+            *out = Buffer::from_vec(bytes);
+            true
+        }
+        _ => {
+            *out = Buffer::empty();
+            false
+        }
+    }
+}
+
+/// Constructs a `Prover` from a `bincode`-serialized `NoirProofScheme`.
+///
+/// On success, writes an opaque handle to `*out_prover`; the caller owns it
+/// and must release it with [`provekit_prover_free`]. Returns `false` (and
+/// leaves `*out_prover` untouched) on malformed input.
+///
+/// # Safety
+/// `scheme.data` must point to `scheme.len` valid bytes. `out_prover` must be
+/// a valid, non-null pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn provekit_prover_new(scheme: Buffer, out_prover: *mut *mut Prover<FieldElement>) -> bool {
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        bincode::deserialize::<NoirProofScheme<FieldElement>>(scheme.as_slice())
+    }));
+    match result {
+        Ok(Ok(scheme)) => {
+            let prover = Box::new(Prover::from_noir_proof_scheme(scheme));
+            *out_prover = Box::into_raw(prover);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Releases a `Prover` handle created by [`provekit_prover_new`].
+///
+/// # Safety
+/// `prover` must either be null or a handle previously returned by
+/// [`provekit_prover_new`] that has not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn provekit_prover_free(prover: *mut Prover<FieldElement>) {
+    if !prover.is_null() {
+        drop(Box::from_raw(prover));
+    }
+}
+
+/// Runs witness generation for `input_toml` (the contents of a Prover.toml
+/// style input file) and writes the `bincode`-serialized ACIR witness map to
+/// `*out_witness` on success.
+///
+/// # Safety
+/// `prover` must be a live handle from [`provekit_prover_new`].
+/// `input_toml.data` must point to `input_toml.len` valid bytes. `out_witness`
+/// must be a valid, non-null pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn provekit_generate_witness(
+    prover: *mut Prover<FieldElement>,
+    input_toml: Buffer,
+    out_witness: *mut Buffer,
+) -> bool {
+    let Some(prover) = prover.as_mut() else {
+        return false;
+    };
+    catch(&mut *out_witness, || {
+        let input_map = parse_input_map(prover, input_toml.as_slice())?;
+        let witness = prover.generate_witness(input_map)?;
+        Ok(bincode::serialize(&witness)?)
+    })
+}
+
+/// Runs the full proving pipeline for `input_toml` and writes the
+/// `bincode`-serialized [`NoirProof`] to `*out_proof` on success.
+///
+/// # Safety
+/// `prover` must be a live handle from [`provekit_prover_new`].
+/// `input_toml.data` must point to `input_toml.len` valid bytes. `out_proof`
+/// must be a valid, non-null pointer to write to.
+#[no_mangle]
+pub unsafe extern "C" fn provekit_prove(
+    prover: *mut Prover<FieldElement>,
+    input_toml: Buffer,
+    out_proof: *mut Buffer,
+) -> bool {
+    let Some(prover) = prover.as_mut() else {
+        return false;
+    };
+    catch(&mut *out_proof, || {
+        let proof = prove_from_bytes(prover, input_toml.as_slice(), MaskingMode::Random)?;
+        Ok(bincode::serialize(&proof)?)
+    })
+}
+
+/// `read_inputs_from_file`/`prove` only take a path, so FFI callers that
+/// hand us in-memory TOML bytes get bridged through a scratch file.
+fn prove_from_bytes(prover: &mut Prover<FieldElement>, input_toml: &[u8], masking: MaskingMode) -> anyhow::Result<NoirProof> {
+    let scratch = write_scratch_toml(input_toml)?;
+    prover.prove_with_masking(scratch.path(), masking)
+}
+
+fn parse_input_map(
+    prover: &Prover<FieldElement>,
+    input_toml: &[u8],
+) -> anyhow::Result<noirc_abi::InputMap> {
+    let scratch = write_scratch_toml(input_toml)?;
+    let (input_map, _) =
+        noir_artifact_cli::fs::inputs::read_inputs_from_file(scratch.path(), prover.witness_generator()?.abi())?;
+    Ok(input_map)
+}
+
+/// Writes `contents` to a freshly created, exclusively-owned temp file, so
+/// concurrent FFI calls (the whole point of this surface) can't collide on
+/// the same path or read each other's witness input. The file is removed
+/// automatically when the returned handle is dropped. The file gets a
+/// `.toml` suffix, since `read_inputs_from_file` picks the input format from
+/// the file extension.
+fn write_scratch_toml(contents: &[u8]) -> anyhow::Result<NamedTempFile> {
+    let mut file = tempfile::Builder::new().suffix(".toml").tempfile()?;
+    file.write_all(contents)?;
+    Ok(file)
+}
@@ -15,23 +15,53 @@ use {
         skyscraper::SkyscraperSponge,
         utils::noir_to_native,
         witness::{LayeredWitnessBuilders, PublicInputs, WitnessBuilder},
-        FieldElement, IOPattern, NoirElement, NoirProof, Prover,
+        FieldElement, IOPattern, NoirElement, NoirProof, Prover, ProverError,
     },
     spongefish::{codecs::arkworks_algebra::FieldToUnitSerialize, ProverState},
     std::{iter::once, path::Path},
     tracing::instrument,
 };
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
 mod r1cs;
+mod solidity_verifier;
 mod whir_r1cs;
 mod witness;
 
+pub use solidity_verifier::{generate_solidity_verifier, SolidityVerifier};
+
+/// How the zero-knowledge mask added to the witness polynomial is generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MaskingMode {
+    /// Draw mask coefficients from OS entropy. Two proofs of the same
+    /// witness will differ.
+    #[default]
+    Random,
+    /// Derive the mask seed from the witness-seeded Fiat-Shamir sponge, so
+    /// the same witness and circuit always reproduce the same proof. Useful
+    /// for testing, while still keeping the mask hidden from a verifier.
+    ///
+    /// NOT YET IMPLEMENTED: `WhirR1CSScheme::prove` doesn't take a mask seed,
+    /// so there's nowhere to plug the derived seed into. `prove_with_masking`
+    /// rejects this variant with [`ProverError::DeterministicMaskingUnsupported`]
+    /// rather than silently falling back to [`MaskingMode::Random`].
+    Deterministic,
+}
+
 pub trait Prove {
     fn generate_witness(&mut self, input_map: InputMap) -> Result<WitnessMap<NoirElement>>;
 
+    /// Equivalent to `prove_with_masking(prover_toml, MaskingMode::Random)`.
     fn prove(&mut self, prover_toml: impl AsRef<Path>) -> Result<NoirProof>;
 
-    fn create_witness_io_pattern(&self) -> IOPattern;
+    fn prove_with_masking(
+        &mut self,
+        prover_toml: impl AsRef<Path>,
+        masking: MaskingMode,
+    ) -> Result<NoirProof>;
+
+    fn create_witness_io_pattern(&self) -> Result<IOPattern>;
 
     fn seed_witness_merlin(
         &mut self,
@@ -40,7 +70,15 @@ pub trait Prove {
     ) -> Result<()>;
 }
 
-impl Prove for Prover {
+// The WHIR transcript (`SkyscraperSponge`) this pipeline seeds is specific to
+// `FieldElement`, so `Prove` is implemented for that field rather than for
+// `Prover<F>` generically. The `r1cs`/`whir_r1cs` modules backing the
+// `R1CSSolver`/`WhirR1CSProver` traits this impl relies on (imported above)
+// were likewise left as-is for `FieldElement` only: they're out of scope for
+// this genericity pass and not touched by it. Only `Prover`'s own data
+// fields and the polynomial helpers in `zk_utils.rs` were parameterized over
+// `F` — see the caveat on `Prover`'s doc comment.
+impl Prove for Prover<FieldElement> {
     #[instrument(skip_all)]
     fn generate_witness(&mut self, input_map: InputMap) -> Result<WitnessMap<NoirElement>> {
         let solver = Bn254BlackBoxSolver::default();
@@ -55,18 +93,17 @@ impl Prove for Prover {
         .build();
 
         let initial_witness = self
-            .witness_generator
-            .take()
-            .unwrap()
+            .witness_generator()?
             .abi()
             .encode(&input_map, None)?;
 
         let mut witness_stack = nargo::ops::execute_program(
-            &self.program.as_ref().unwrap(),
+            self.program()?,
             initial_witness,
             &solver,
             &mut foreign_call_executor,
-        )?;
+        )
+        .map_err(|err| ProverError::WitnessGenerationFailed(err.to_string()))?;
 
         Ok(witness_stack
             .pop()
@@ -76,33 +113,39 @@ impl Prove for Prover {
 
     #[instrument(skip_all)]
     fn prove(&mut self, prover_toml: impl AsRef<Path>) -> Result<NoirProof> {
-        let (input_map, _expected_return) = read_inputs_from_file(
-            prover_toml.as_ref(),
-            self.witness_generator.as_ref().unwrap().abi(),
-        )?;
+        self.prove_with_masking(prover_toml, MaskingMode::Random)
+    }
+
+    #[instrument(skip_all)]
+    fn prove_with_masking(&mut self, prover_toml: impl AsRef<Path>, masking: MaskingMode) -> Result<NoirProof> {
+        if masking == MaskingMode::Deterministic {
+            return Err(ProverError::DeterministicMaskingUnsupported.into());
+        }
+
+        let (input_map, _expected_return) =
+            read_inputs_from_file(prover_toml.as_ref(), self.witness_generator()?.abi())?;
 
         let acir_witness_idx_to_value_map = self.generate_witness(input_map)?;
-        let acir_public_inputs = self.program.as_ref().unwrap().functions[0]
-            .public_inputs()
-            .indices();
+        let acir_public_inputs = self.program()?.functions[0].public_inputs().indices();
 
         // Solve R1CS instance
-        let witness_io = self.create_witness_io_pattern();
+        let witness_io = self.create_witness_io_pattern()?;
         let mut witness_merlin = witness_io.to_prover_state();
         self.seed_witness_merlin(&mut witness_merlin, &acir_witness_idx_to_value_map)?;
 
-        let split_witness_builders = self.split_witness_builders.take().unwrap();
-        let mut all_layers = split_witness_builders.w1_layers.layers;
-        all_layers.extend(split_witness_builders.w2_layers.layers);
+        let split_witness_builders = self.split_witness_builders()?;
+        let mut all_layers = split_witness_builders.w1_layers.layers.clone();
+        all_layers.extend(split_witness_builders.w2_layers.layers.clone());
         let layered_witness_builders = LayeredWitnessBuilders { layers: all_layers };
 
-        let (partial_witness, acir_to_r1cs_public_map) =
-            self.r1cs.as_ref().unwrap().solve_witness_vec(
-                layered_witness_builders,
-                acir_witness_idx_to_value_map,
-                &acir_public_inputs,
-                &mut witness_merlin,
-            );
+        // Solve against the `Prover`'s cached `R1CS` so a server that reuses
+        // the same `Prover` across many proofs only loads/compiles it once.
+        let (partial_witness, acir_to_r1cs_public_map) = self.prepared_r1cs()?.r1cs.solve_witness_vec(
+            layered_witness_builders,
+            acir_witness_idx_to_value_map,
+            &acir_public_inputs,
+            &mut witness_merlin,
+        );
 
         let public_indices = acir_to_r1cs_public_map
             .values()
@@ -112,9 +155,8 @@ impl Prove for Prover {
 
         // Verify witness (redudant with solve)
         #[cfg(test)]
-        self.r1cs
-            .as_ref()
-            .unwrap()
+        self.prepared_r1cs()?
+            .r1cs
             .test_witness_satisfaction(&witness)
             .context("While verifying R1CS instance")?;
 
@@ -126,12 +168,13 @@ impl Prove for Prover {
                 .collect::<Vec<FieldElement>>(),
         );
 
-        // Prove R1CS instance
+        // Prove R1CS instance. `whir_for_witness` and the prepared `r1cs` are
+        // cloned rather than taken so that this `Prover` can be reused for
+        // further proofs.
         let whir_r1cs_proof = self
-            .whir_for_witness
-            .take()
-            .unwrap()
-            .prove(self.r1cs.take().unwrap(), witness, &public_inputs)
+            .whir_for_witness()?
+            .clone()
+            .prove(self.prepared_r1cs()?.r1cs.clone(), witness, &public_inputs)
             .context("While proving R1CS instance")?;
 
         Ok(NoirProof {
@@ -140,13 +183,11 @@ impl Prove for Prover {
         })
     }
 
-    fn create_witness_io_pattern(&self) -> IOPattern {
-        let circuit = &self.program.as_ref().unwrap().functions[0];
+    fn create_witness_io_pattern(&self) -> Result<IOPattern> {
+        let circuit = &self.program()?.functions[0];
         let public_idxs = circuit.public_inputs().indices();
         let num_challenges = self
-            .split_witness_builders
-            .as_ref()
-            .unwrap()
+            .split_witness_builders()?
             .w2_layers
             .layers
             .iter()
@@ -155,10 +196,10 @@ impl Prove for Prover {
             .count();
 
         // Create witness IO pattern
-        IOPattern::new("📜")
+        Ok(IOPattern::new("📜")
             .add_shape()
             .add_public_inputs(public_idxs.len())
-            .add_logup_challenges(num_challenges)
+            .add_logup_challenges(num_challenges))
     }
 
     fn seed_witness_merlin(
@@ -167,13 +208,14 @@ impl Prove for Prover {
         witness: &WitnessMap<NoirElement>,
     ) -> Result<()> {
         // Absorb circuit shape
+        let r1cs = self.r1cs()?;
         let _ = merlin.add_scalars(&[
-            FieldElement::from(self.r1cs.as_ref().unwrap().num_constraints() as u64),
-            FieldElement::from(self.r1cs.as_ref().unwrap().num_witnesses() as u64),
+            FieldElement::from(r1cs.num_constraints() as u64),
+            FieldElement::from(r1cs.num_witnesses() as u64),
         ]);
 
         // Absorb public inputs (values) in canonical order
-        let circuit = &self.program.take().unwrap().functions[0];
+        let circuit = &self.program()?.functions[0];
         let public_idxs = circuit.public_inputs().indices();
         if !public_idxs.is_empty() {
             let pub_vals: Vec<FieldElement> = public_idxs
@@ -0,0 +1,121 @@
+//! Calldata-encoding scaffolding for an on-chain [`NoirProof`] verifier.
+//! **This does not verify anything yet** — see below.
+//!
+//! This module only does two things: renders a Solidity contract shell with
+//! the circuit shape (`num_constraints`/`num_witnesses`) baked in as
+//! constants, and ABI-encodes a `proof` into the calldata layout that shell
+//! expects (public inputs, then the serialized WHIR-over-R1CS proof). None of
+//! the actual verification logic — replaying the `SkyscraperSponge`
+//! Fiat-Shamir transcript `seed_witness_merlin`/`WhirR1CSProver::prove` build
+//! up, re-deriving the sumcheck round challenges, checking the final WHIR PCS
+//! opening — is implemented: the generated contract's `verify` reverts
+//! unconditionally rather than accepting any proof as valid. Do not treat
+//! this as a finished on-chain verifier; it exists to pin down the calldata
+//! layout ahead of that real replay.
+
+use {
+    anyhow::{Context, Result},
+    provekit_common::{FieldElement, NoirProof, Prover},
+};
+
+/// A generated contract shell (see the module docs — `verify` always
+/// reverts) plus the calldata blob that would be passed to its `verify`
+/// entrypoint for this specific proof, once a real implementation exists.
+#[derive(Debug, Clone)]
+pub struct SolidityVerifier {
+    /// Solidity source for the contract shell. `verify` always reverts; see
+    /// the module docs.
+    pub source:   String,
+    /// The `proof` argument for `verify(bytes)` (without the function
+    /// selector or ABI offset/length header): the public inputs followed by
+    /// the serialized WHIR-over-R1CS proof.
+    pub calldata: Vec<u8>,
+}
+
+/// Builds the calldata-encoding contract shell (not a working verifier — see
+/// the module docs) for `proof`, produced by `prover`.
+///
+/// The circuit shape absorbed into the transcript via `seed_witness_merlin`
+/// (number of constraints and witnesses) is hardcoded into the generated
+/// contract, so the contract shell only matches this exact circuit.
+pub fn generate_solidity_verifier(
+    prover: &Prover<FieldElement>,
+    proof: &NoirProof,
+) -> Result<SolidityVerifier> {
+    let r1cs = prover.r1cs().context("While generating Solidity verifier")?;
+    let num_constraints = r1cs.num_constraints();
+    let num_witnesses = r1cs.num_witnesses();
+    let num_public_inputs = proof.public_inputs.as_vec().len();
+
+    let source = render_contract(num_constraints, num_witnesses, num_public_inputs);
+    let calldata = encode_calldata(proof)?;
+
+    Ok(SolidityVerifier { source, calldata })
+}
+
+/// Renders the verifier contract source, with the circuit shape and public
+/// input count spliced in as constants.
+fn render_contract(num_constraints: usize, num_witnesses: usize, num_public_inputs: usize) -> String {
+    format!(
+        r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// Auto-generated calldata-layout shell for a WHIR-over-R1CS verifier.
+/// NOT A WORKING VERIFIER: `verify` always reverts, see below. Do not edit
+/// by hand; regenerate from the `Prover`/`NoirProof` pair that produced it.
+contract NoirWhirVerifier {{
+    uint256 constant NUM_CONSTRAINTS = {num_constraints};
+    uint256 constant NUM_WITNESSES = {num_witnesses};
+    uint256 constant NUM_PUBLIC_INPUTS = {num_public_inputs};
+
+    /// Checks that `proof` is longer than the public input array, then
+    /// reverts. Replaying the Skyscraper-sponge Fiat-Shamir transcript
+    /// (circuit shape, then public inputs, then logup challenges),
+    /// re-deriving the sumcheck round challenges and checking the final WHIR
+    /// PCS opening are not implemented.
+    ///
+    /// `proof` is the calldata-encoded public input array followed by the
+    /// serialized WHIR-over-R1CS proof, as produced by
+    /// `generate_solidity_verifier`.
+    ///
+    /// NOT YET IMPLEMENTED: the transcript replay below is a stub that always
+    /// reverts. Do not deploy this contract expecting it to check proofs; it
+    /// exists to pin down the calldata layout ahead of the real replay.
+    function verify(bytes calldata proof) external pure returns (bool) {{
+        // NUM_PUBLIC_INPUTS * 32 bytes of public inputs, then the WHIR
+        // transcript: sumcheck round polynomials and the final PCS opening.
+        require(proof.length > NUM_PUBLIC_INPUTS * 32, "proof too short");
+        return _verifyTranscript(proof);
+    }}
+
+    function _verifyTranscript(bytes calldata) private pure returns (bool) {{
+        // The Skyscraper sponge absorbs/squeezes and WHIR sumcheck/PCS
+        // checks described on `verify` are not implemented yet. Fail closed
+        // rather than accept arbitrary calldata as a valid proof.
+        revert("NoirWhirVerifier: transcript replay not implemented");
+    }}
+}}
+"#
+    )
+}
+
+/// Encodes the `proof` argument for `verify`: the public inputs as 32-byte
+/// big-endian words, followed by the `bincode`-serialized proof.
+fn encode_calldata(proof: &NoirProof) -> Result<Vec<u8>> {
+    let mut calldata = Vec::new();
+    for input in proof.public_inputs.as_vec() {
+        calldata.extend_from_slice(&field_to_be_bytes(input));
+    }
+    calldata.extend(bincode::serialize(&proof.whir_r1cs_proof).context("While serializing WHIR proof")?);
+    Ok(calldata)
+}
+
+/// Encodes a field element as a 32-byte big-endian word, as the EVM expects.
+fn field_to_be_bytes(value: &FieldElement) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    let le = ark_ff::BigInteger::to_bytes_le(&ark_ff::PrimeField::into_bigint(*value));
+    for (i, byte) in le.iter().take(32).enumerate() {
+        bytes[31 - i] = *byte;
+    }
+    bytes
+}